@@ -2,15 +2,87 @@
 
 #[ink::contract]
 mod swap_contract {
+    use ink::env::call::{build_call, ExecutionInput, Selector};
+    use ink::env::hash::{HashOutput, Keccak256};
+    use ink::prelude::vec::Vec;
     use ink::storage::Mapping;
 
+    /// Selectors for the PSP22 token standard messages this contract calls
+    /// cross-contract via `build_call`
+    mod psp22_selectors {
+        /// `PSP22::allowance(owner, spender) -> Balance`
+        pub const ALLOWANCE: [u8; 4] = [0x4d, 0x47, 0xd9, 0x21];
+        /// `PSP22::transfer(to, value, data) -> Result<(), PSP22Error>`
+        pub const TRANSFER: [u8; 4] = [0xdb, 0x20, 0xf9, 0xf5];
+        /// `PSP22::transfer_from(from, to, value, data) -> Result<(), PSP22Error>`
+        pub const TRANSFER_FROM: [u8; 4] = [0x54, 0xb3, 0xc7, 0x6e];
+    }
+
+    /// The PSP22 standard's error type, decoded from `transfer`/`transfer_from`
+    /// replies so a gracefully-returned `Err` isn't mistaken for success
+    #[derive(scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum PSP22Error {
+        Custom(ink::prelude::string::String),
+        InsufficientBalance,
+        InsufficientAllowance,
+        ZeroRecipientAddress,
+        ZeroSenderAddress,
+        SafeTransferCheckFailed(ink::prelude::string::String),
+    }
+
+    /// An asset leg of a swap: either the contract's native token or a PSP22 token
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum AssetKind {
+        /// The chain's native balance
+        Native(Balance),
+        /// A PSP22 fungible token identified by its contract address
+        Psp22 { token: AccountId, amount: Balance },
+    }
+
+    impl AssetKind {
+        /// The amount of the asset being swapped, regardless of kind
+        fn amount(&self) -> Balance {
+            match self {
+                AssetKind::Native(amount) => *amount,
+                AssetKind::Psp22 { amount, .. } => *amount,
+            }
+        }
+    }
+
+    /// Lifecycle of a swap, mirroring the reserve/release model of a balance
+    /// that is locked for a specific purpose rather than moved away outright
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum SwapStatus {
+        /// Recorded by `initiate_swap` but not yet escrowed
+        Pending,
+        /// Initiator's leg has been escrowed via `fund_swap`; acceptable
+        Funded,
+        /// Accepted, with both legs released, or HTLC-claimed, with the
+        /// initiator's leg released to the claimant
+        Completed,
+        /// Cancelled by the initiator
+        Cancelled,
+    }
+
     /// Represents a swap agreement between two parties
     /// # Fields
     /// - initiator: Account that created the swap
     /// - counterparty: Account that can accept the swap
-    /// - initiator_asset: Amount of native token deposited by initiator
-    /// - counterparty_asset: Required amount from counterparty to complete swap
-    #[derive(scale::Decode, scale::Encode)]
+    /// - initiator_asset: Asset (native or PSP22) escrowed by the initiator
+    /// - counterparty_asset: Asset (native or PSP22) required from the counterparty
+    /// - hashlock: Keccak256 hash of the secret preimage, for HTLC swaps
+    /// - timelock: Block number from which on the initiator may reclaim an unclaimed HTLC swap
+    /// - status: Where the swap sits in its Pending/Funded/Completed/Cancelled lifecycle
+    #[derive(Clone, Debug, PartialEq, Eq, scale::Decode, scale::Encode)]
     #[cfg_attr(
         feature = "std",
         derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
@@ -18,10 +90,16 @@ mod swap_contract {
     pub struct Swap {
         initiator: AccountId,
         counterparty: AccountId,
-        initiator_asset: Balance,
-        counterparty_asset: Balance,
+        initiator_asset: AssetKind,
+        counterparty_asset: AssetKind,
+        hashlock: Option<[u8; 32]>,
+        timelock: Option<BlockNumber>,
+        status: SwapStatus,
     }
 
+    /// Upper bound on the protocol fee, in basis points (10% of a swap's amount)
+    const MAX_FEE_BPS: u16 = 1_000;
+
     /// Main contract storage structure
     #[ink(storage)]
     pub struct SwapContract {
@@ -30,6 +108,19 @@ mod swap_contract {
         /// Auto-incrementing ID counter for new swaps
         next_swap_id: u32,
         reentrancy_guard: bool, // Add this line
+        /// Account allowed to change the protocol fee
+        owner: AccountId,
+        /// Protocol fee charged on each completed swap, in basis points
+        fee_bps: u16,
+        /// Account that receives collected protocol fees
+        fee_recipient: AccountId,
+        /// Swap IDs where each account is the initiator, including past swaps
+        swaps_by_initiator: Mapping<AccountId, Vec<u32>>,
+        /// Swap IDs where each account is the counterparty, including past swaps
+        swaps_by_counterparty: Mapping<AccountId, Vec<u32>>,
+        /// Maps a negotiated swap ID (derived from the parties and a salt) to
+        /// the counter-based swap ID it was created with
+        negotiated_swaps: Mapping<[u8; 32], u32>,
     }
 
     /// Custom error types for swap operations
@@ -48,6 +139,29 @@ mod swap_contract {
         SwapIdOverflow = 4,
         /// Reentrancy detected
         Reentrancy = 5,
+        /// Preimage does not hash to the swap's hashlock
+        InvalidPreimage = 6,
+        /// Initiator may only reclaim an HTLC swap once its timelock has expired
+        TimelockNotExpired = 7,
+        /// Operation requires (or forbids) an HTLC swap and the swap is the wrong kind
+        NotHtlcSwap = 8,
+        /// A PSP22 cross-contract call (transfer or transfer_from) failed
+        TokenTransferFailed = 9,
+        /// Caller has not approved the contract to move enough PSP22 tokens
+        InsufficientAllowance = 10,
+        /// Requested fee exceeds `MAX_FEE_BPS`
+        FeeTooHigh = 11,
+        /// Fee computation overflowed
+        FeeOverflow = 12,
+        /// Swap exists but is not `Funded`: not yet escrowed via `fund_swap`,
+        /// or already completed or cancelled
+        SwapNotFunded = 13,
+        /// Swap has already been funded and cannot be funded again
+        SwapAlreadyFunded = 14,
+        /// A swap with this negotiated (initiator, counterparty, salt) already exists
+        SwapAlreadyExists = 15,
+        /// Native value was attached to a call settling a PSP22 leg, which would be stranded
+        UnexpectedValueTransferred = 16,
     }
 
     /// Emitted when a new swap is created
@@ -57,8 +171,8 @@ mod swap_contract {
         swap_id: u32,
         initiator: AccountId,
         counterparty: AccountId,
-        initiator_asset: Balance,
-        counterparty_asset: Balance,
+        initiator_asset: AssetKind,
+        counterparty_asset: AssetKind,
     }
 
     /// Emitted when a swap is successfully completed
@@ -66,6 +180,10 @@ mod swap_contract {
     pub struct SwapAccepted {
         #[ink(topic)]
         swap_id: u32,
+        /// Protocol fee skimmed from the initiator's leg
+        initiator_fee: Balance,
+        /// Protocol fee skimmed from the counterparty's leg
+        counterparty_fee: Balance,
     }
 
     /// Emitted when a swap is canceled by initiator
@@ -75,6 +193,17 @@ mod swap_contract {
         swap_id: u32,
     }
 
+    /// Emitted when an HTLC swap is claimed, revealing the preimage on-chain
+    /// so a counterparty on the other chain can extract it and claim their leg
+    #[ink(event)]
+    pub struct SwapClaimed {
+        #[ink(topic)]
+        swap_id: u32,
+        preimage: Vec<u8>,
+        /// Protocol fee skimmed from the initiator's leg before release
+        fee: Balance,
+    }
+
     //----------------------------------
     // Default Implementation
     //----------------------------------
@@ -87,15 +216,63 @@ mod swap_contract {
                 swaps: Mapping::default(),
                 next_swap_id: 0,
                 reentrancy_guard: false,
+                owner: AccountId::from([0u8; 32]),
+                fee_bps: 0,
+                fee_recipient: AccountId::from([0u8; 32]),
+                swaps_by_initiator: Mapping::default(),
+                swaps_by_counterparty: Mapping::default(),
+                negotiated_swaps: Mapping::default(),
             }
         }
     }
 
     impl SwapContract {
-        /// Creates a new swap contract with empty state
+        /// Creates a new swap contract with empty state and no protocol fee
         #[ink(constructor)]
         pub fn new() -> Self {
-            Self::default()
+            let mut contract = Self::default();
+            contract.owner = Self::env().caller();
+            contract
+        }
+
+        /// Creates a new swap contract that skims a protocol fee on every completed swap
+        /// # Arguments
+        /// - fee_bps: Fee rate in basis points, must not exceed `MAX_FEE_BPS`
+        /// - fee_recipient: Account that receives collected fees
+        #[ink(constructor)]
+        pub fn new_with_fee(fee_bps: u16, fee_recipient: AccountId) -> Result<Self, Error> {
+            if fee_bps > MAX_FEE_BPS {
+                return Err(Error::FeeTooHigh);
+            }
+
+            let mut contract = Self::default();
+            contract.owner = Self::env().caller();
+            contract.fee_bps = fee_bps;
+            contract.fee_recipient = fee_recipient;
+            Ok(contract)
+        }
+
+        /// Updates the protocol fee rate and recipient
+        /// # Arguments
+        /// - fee_bps: New fee rate in basis points, must not exceed `MAX_FEE_BPS`
+        /// - fee_recipient: Account that receives collected fees
+        /// # Returns
+        /// - Ok(()): Success
+        /// - Err(Error): Failure reason
+        /// # Note
+        /// Caller must be the contract owner
+        #[ink(message)]
+        pub fn set_fee(&mut self, fee_bps: u16, fee_recipient: AccountId) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotAuthorized);
+            }
+            if fee_bps > MAX_FEE_BPS {
+                return Err(Error::FeeTooHigh);
+            }
+
+            self.fee_bps = fee_bps;
+            self.fee_recipient = fee_recipient;
+            Ok(())
         }
 
         /// Transfers funds to the specified account
@@ -109,14 +286,123 @@ mod swap_contract {
             self.env().transfer(to, amount).map_err(|_| Error::InsufficientCounterpartyBalance)
         }
 
+        /// Queries how many PSP22 tokens `owner` has approved this contract to move
+        fn psp22_allowance(&self, token: AccountId, owner: AccountId) -> Result<Balance, Error> {
+            build_call::<Environment>()
+                .call(token)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(psp22_selectors::ALLOWANCE))
+                        .push_arg(owner)
+                        .push_arg(self.env().account_id()),
+                )
+                .returns::<Balance>()
+                .try_invoke()
+                .map_err(|_| Error::TokenTransferFailed)?
+                .map_err(|_| Error::TokenTransferFailed)
+        }
+
+        /// Pulls `amount` of a PSP22 token from `from` into this contract's balance
+        /// # Note
+        /// Requires `from` to have approved this contract beforehand
+        fn psp22_transfer_from(&self, token: AccountId, from: AccountId, amount: Balance) -> Result<(), Error> {
+            build_call::<Environment>()
+                .call(token)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(psp22_selectors::TRANSFER_FROM))
+                        .push_arg(from)
+                        .push_arg(self.env().account_id())
+                        .push_arg(amount)
+                        .push_arg(Vec::<u8>::new()),
+                )
+                .returns::<Result<(), PSP22Error>>()
+                .try_invoke()
+                .map_err(|_| Error::TokenTransferFailed)?
+                .map_err(|_| Error::TokenTransferFailed)?
+                .map_err(|_| Error::TokenTransferFailed)
+        }
+
+        /// Sends `amount` of a PSP22 token held by this contract to `to`
+        fn psp22_transfer(&self, token: AccountId, to: AccountId, amount: Balance) -> Result<(), Error> {
+            build_call::<Environment>()
+                .call(token)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(psp22_selectors::TRANSFER))
+                        .push_arg(to)
+                        .push_arg(amount)
+                        .push_arg(Vec::<u8>::new()),
+                )
+                .returns::<Result<(), PSP22Error>>()
+                .try_invoke()
+                .map_err(|_| Error::TokenTransferFailed)?
+                .map_err(|_| Error::TokenTransferFailed)?
+                .map_err(|_| Error::TokenTransferFailed)
+        }
+
+        /// Pulls a PSP22 leg into escrow from `from`, checking its allowance first
+        fn escrow_psp22(&self, token: AccountId, from: AccountId, amount: Balance) -> Result<(), Error> {
+            if self.psp22_allowance(token, from)? < amount {
+                return Err(Error::InsufficientAllowance);
+            }
+            self.psp22_transfer_from(token, from, amount)
+        }
+
+        /// Releases a previously escrowed asset leg to `to`
+        fn release_asset(&self, asset: AssetKind, to: AccountId) -> Result<(), Error> {
+            match asset {
+                AssetKind::Native(amount) => self.transfer_funds(to, amount),
+                AssetKind::Psp22 { token, amount } => self.psp22_transfer(token, to, amount),
+            }
+        }
+
+        /// Releases a previously escrowed asset leg to `to`, skimming the
+        /// protocol fee to `fee_recipient` first
+        /// # Returns
+        /// - Ok(Balance): The fee amount collected
+        fn release_asset_with_fee(&self, asset: AssetKind, to: AccountId) -> Result<Balance, Error> {
+            let amount = asset.amount();
+            let fee = amount
+                .checked_mul(self.fee_bps as Balance)
+                .and_then(|scaled| scaled.checked_div(10_000))
+                .ok_or(Error::FeeOverflow)?;
+            let remainder = amount.checked_sub(fee).ok_or(Error::FeeOverflow)?;
+
+            match asset {
+                AssetKind::Native(_) => {
+                    if fee > 0 {
+                        self.transfer_funds(self.fee_recipient, fee)?;
+                    }
+                    self.transfer_funds(to, remainder)?;
+                }
+                AssetKind::Psp22 { token, .. } => {
+                    if fee > 0 {
+                        self.psp22_transfer(token, self.fee_recipient, fee)?;
+                    }
+                    self.psp22_transfer(token, to, remainder)?;
+                }
+            }
+
+            Ok(fee)
+        }
+
+        /// Records a newly created swap in the per-account indexes
+        fn index_swap(&mut self, initiator: AccountId, counterparty: AccountId, swap_id: u32) {
+            let mut initiator_swaps = self.swaps_by_initiator.get(initiator).unwrap_or_default();
+            initiator_swaps.push(swap_id);
+            self.swaps_by_initiator.insert(initiator, &initiator_swaps);
+
+            let mut counterparty_swaps = self.swaps_by_counterparty.get(counterparty).unwrap_or_default();
+            counterparty_swaps.push(swap_id);
+            self.swaps_by_counterparty.insert(counterparty, &counterparty_swaps);
+        }
+
         /// Emits an event indicating that a new swap has been initiated
         /// # Arguments
         /// - `swap_id`: The ID of the newly created swap
         /// - `initiator`: The account that initiated the swap
         /// - `counterparty`: The account that can accept the swap
-        /// - `initiator_asset`: The amount of native token deposited by the initiator
-        /// - `counterparty_asset`: The required amount from the counterparty to complete the swap
-        fn emit_swap_initiated(&self, swap_id: u32, initiator: AccountId, counterparty: AccountId, initiator_asset: Balance, counterparty_asset: Balance) {
+        /// - `initiator_asset`: The asset escrowed by the initiator
+        /// - `counterparty_asset`: The asset required from the counterparty to complete the swap
+        fn emit_swap_initiated(&self, swap_id: u32, initiator: AccountId, counterparty: AccountId, initiator_asset: AssetKind, counterparty_asset: AssetKind) {
             self.env().emit_event(SwapInitiated {
                 swap_id,
                 initiator,
@@ -129,8 +415,10 @@ mod swap_contract {
         /// Emits an event indicating that a swap has been accepted
         /// # Arguments
         /// - `swap_id`: The ID of the accepted swap
-        fn emit_swap_accepted(&self, swap_id: u32) {
-            self.env().emit_event(SwapAccepted { swap_id });
+        /// - `initiator_fee`: Protocol fee skimmed from the initiator's leg
+        /// - `counterparty_fee`: Protocol fee skimmed from the counterparty's leg
+        fn emit_swap_accepted(&self, swap_id: u32, initiator_fee: Balance, counterparty_fee: Balance) {
+            self.env().emit_event(SwapAccepted { swap_id, initiator_fee, counterparty_fee });
         }
 
         /// Emits an event indicating that a swap has been cancelled
@@ -140,26 +428,38 @@ mod swap_contract {
             self.env().emit_event(SwapCancelled { swap_id });
         }
 
-        /// Creates a new swap agreement
+        /// Emits an event revealing the preimage that claimed an HTLC swap
+        /// # Arguments
+        /// - `swap_id`: The ID of the claimed swap
+        /// - `preimage`: The secret that hashes to the swap's hashlock
+        /// - `fee`: Protocol fee skimmed from the initiator's leg before release
+        fn emit_swap_claimed(&self, swap_id: u32, preimage: Vec<u8>, fee: Balance) {
+            self.env().emit_event(SwapClaimed { swap_id, preimage, fee });
+        }
+
+        /// Records a new swap agreement without moving any funds
         /// # Arguments
         /// - counterparty: Account that can accept the swap
-        /// - counterparty_asset: Required deposit from counterparty
+        /// - initiator_asset: Asset to be escrowed by the initiator (native or PSP22)
+        /// - counterparty_asset: Asset required from the counterparty to complete the swap
         /// # Returns
         /// - Ok(u32): Newly created swap ID
         /// - Err(Error): Failure reason
         /// # Note
-        /// Caller must send native tokens equal to initiator_asset
-        #[ink(message, payable)]
+        /// The swap starts `Pending` and holds no funds: the initiator's deposit
+        /// stays in their own balance until they call `fund_swap`. This keeps the
+        /// window where the contract pools tokens limited to swaps that are
+        /// actually live, rather than every intent that gets created.
+        #[ink(message)]
         pub fn initiate_swap(
             &mut self,
             counterparty: AccountId,
-            counterparty_asset: Balance
+            initiator_asset: AssetKind,
+            counterparty_asset: AssetKind,
         ) -> Result<u32, Error> {
             let initiator = self.env().caller();
-            let initiator_asset = self.env().transferred_value();
 
-            // Validate initiator's deposit
-            if initiator_asset == 0 {
+            if initiator_asset.amount() == 0 {
                 return Err(Error::InsufficientInitiatorBalance);
             }
 
@@ -172,15 +472,187 @@ mod swap_contract {
                 counterparty,
                 initiator_asset,
                 counterparty_asset,
+                hashlock: None,
+                timelock: None,
+                status: SwapStatus::Pending,
             };
 
             self.swaps.insert(swap_id, &swap);
+            self.index_swap(initiator, counterparty, swap_id);
 
             self.emit_swap_initiated(swap_id, initiator, counterparty, initiator_asset, counterparty_asset);
 
             Ok(swap_id)
         }
 
+        /// Records a new swap agreement like `initiate_swap`, but under a
+        /// negotiated ID derived from the parties and a caller-chosen salt
+        /// # Arguments
+        /// - counterparty: Account that can accept the swap
+        /// - initiator_asset: Asset to be escrowed by the initiator (native or PSP22)
+        /// - counterparty_asset: Asset required from the counterparty to complete the swap
+        /// - salt: Value agreed on with the counterparty ahead of time
+        /// # Returns
+        /// - Ok(u32): Newly created swap ID
+        /// - Err(Error::SwapAlreadyExists): A swap with this (initiator, counterparty, salt) already exists
+        /// - Err(Error): Any other `initiate_swap` failure reason
+        /// # Note
+        /// Both parties can compute `negotiated_swap_id(initiator, counterparty, salt)`
+        /// off-chain before this transaction lands and agree on the resulting swap
+        /// without racing `next_swap_id`; the swap is still stored and paginated
+        /// under its ordinary counter-based ID, reachable via `swap_id_for_salt`.
+        #[ink(message)]
+        pub fn initiate_swap_with_salt(
+            &mut self,
+            counterparty: AccountId,
+            initiator_asset: AssetKind,
+            counterparty_asset: AssetKind,
+            salt: [u8; 32],
+        ) -> Result<u32, Error> {
+            let initiator = self.env().caller();
+            let negotiated_id = Self::negotiated_swap_id(initiator, counterparty, salt);
+
+            if self.negotiated_swaps.get(negotiated_id).is_some() {
+                return Err(Error::SwapAlreadyExists);
+            }
+
+            let swap_id = self.initiate_swap(counterparty, initiator_asset, counterparty_asset)?;
+            self.negotiated_swaps.insert(negotiated_id, &swap_id);
+
+            Ok(swap_id)
+        }
+
+        /// Looks up the swap ID negotiated from `initiator`, `counterparty` and `salt`
+        #[ink(message)]
+        pub fn swap_id_for_salt(
+            &self,
+            initiator: AccountId,
+            counterparty: AccountId,
+            salt: [u8; 32],
+        ) -> Option<u32> {
+            self.negotiated_swaps.get(Self::negotiated_swap_id(initiator, counterparty, salt))
+        }
+
+        /// Derives a deterministic ID for a swap from both parties' accounts
+        /// and a caller-chosen salt, so it can be agreed on before creation
+        fn negotiated_swap_id(initiator: AccountId, counterparty: AccountId, salt: [u8; 32]) -> [u8; 32] {
+            let mut input = Vec::with_capacity(32 + 32 + 32);
+            input.extend_from_slice(initiator.as_ref());
+            input.extend_from_slice(counterparty.as_ref());
+            input.extend_from_slice(&salt);
+
+            let mut digest = <Keccak256 as HashOutput>::Type::default();
+            ink::env::hash_bytes::<Keccak256>(&input, &mut digest);
+            digest
+        }
+
+        /// Escrows the initiator's leg of a `Pending` swap, making it `Funded`
+        /// and therefore acceptable
+        /// # Arguments
+        /// - swap_id: ID of the swap to fund
+        /// # Returns
+        /// - Ok(()): Success
+        /// - Err(Error): Failure reason
+        /// # Note
+        /// Caller must be the swap's initiator. For a `Native` initiator_asset,
+        /// the caller must send native tokens equal to its amount. For a `Psp22`
+        /// initiator_asset, the caller must have approved this contract to
+        /// transfer_from that amount beforehand.
+        #[ink(message, payable)]
+        pub fn fund_swap(&mut self, swap_id: u32) -> Result<(), Error> {
+            self.enter_reentrancy_guard()?;
+            let result = (|| {
+                let mut swap = self.swaps.get(swap_id).ok_or(Error::SwapNotFound)?;
+
+                if self.env().caller() != swap.initiator {
+                    return Err(Error::NotAuthorized);
+                }
+
+                match swap.status {
+                    SwapStatus::Pending => {}
+                    SwapStatus::Funded => return Err(Error::SwapAlreadyFunded),
+                    SwapStatus::Completed | SwapStatus::Cancelled => return Err(Error::SwapNotFound),
+                }
+
+                match swap.initiator_asset {
+                    AssetKind::Native(amount) => {
+                        if self.env().transferred_value() != amount {
+                            return Err(Error::InsufficientInitiatorBalance);
+                        }
+                    }
+                    AssetKind::Psp22 { token, amount } => {
+                        if self.env().transferred_value() != 0 {
+                            return Err(Error::UnexpectedValueTransferred);
+                        }
+                        self.escrow_psp22(token, swap.initiator, amount)?;
+                    }
+                }
+
+                swap.status = SwapStatus::Funded;
+                self.swaps.insert(swap_id, &swap);
+
+                Ok(())
+            })();
+            self.exit_reentrancy_guard();
+            result
+        }
+
+        /// Creates a hashed-timelock (HTLC) swap for use as one leg of a
+        /// cross-chain atomic swap
+        /// # Arguments
+        /// - counterparty: Account expected to observe the revealed preimage
+        /// - counterparty_asset: Amount owed on the counterparty's chain (informational only)
+        /// - hashlock: Keccak256 hash of the secret preimage
+        /// - timelock: Block number from which on the initiator may reclaim the deposit via `cancel_swap`
+        /// # Returns
+        /// - Ok(u32): Newly created swap ID
+        /// - Err(Error): Failure reason
+        /// # Note
+        /// Caller must send native tokens equal to initiator_asset. The deposit is
+        /// released only to whoever calls `claim` with the matching preimage.
+        #[ink(message, payable)]
+        pub fn initiate_htlc_swap(
+            &mut self,
+            counterparty: AccountId,
+            counterparty_asset: Balance,
+            hashlock: [u8; 32],
+            timelock: BlockNumber,
+        ) -> Result<u32, Error> {
+            let initiator = self.env().caller();
+            let initiator_asset = self.env().transferred_value();
+
+            if initiator_asset == 0 {
+                return Err(Error::InsufficientInitiatorBalance);
+            }
+
+            let swap_id = self.next_swap_id;
+            self.next_swap_id = swap_id.checked_add(1)
+                .ok_or(Error::SwapIdOverflow)?;
+
+            let swap = Swap {
+                initiator,
+                counterparty,
+                initiator_asset: AssetKind::Native(initiator_asset),
+                counterparty_asset: AssetKind::Native(counterparty_asset),
+                hashlock: Some(hashlock),
+                timelock: Some(timelock),
+                status: SwapStatus::Funded,
+            };
+
+            self.swaps.insert(swap_id, &swap);
+            self.index_swap(initiator, counterparty, swap_id);
+
+            self.emit_swap_initiated(
+                swap_id,
+                initiator,
+                counterparty,
+                swap.initiator_asset,
+                swap.counterparty_asset,
+            );
+
+            Ok(swap_id)
+        }
+
         /// Completes an existing swap agreement
         /// # Arguments
         /// - swap_id: ID of swap to complete
@@ -193,7 +665,17 @@ mod swap_contract {
         pub fn accept_swap(&mut self, swap_id: u32) -> Result<(), Error> {
             self.enter_reentrancy_guard()?;
             let result = (|| {
-                let swap = self.swaps.get(swap_id).ok_or(Error::SwapNotFound)?;
+                let mut swap = self.swaps.get(swap_id).ok_or(Error::SwapNotFound)?;
+
+                // HTLC swaps are settled via `claim`, not `accept_swap`
+                if swap.hashlock.is_some() {
+                    return Err(Error::NotHtlcSwap);
+                }
+
+                if swap.status != SwapStatus::Funded {
+                    return Err(Error::SwapNotFunded);
+                }
+
                 let caller = self.env().caller();
 
                 // Authorization check
@@ -201,20 +683,71 @@ mod swap_contract {
                     return Err(Error::NotAuthorized);
                 }
 
-                // Validate counterparty's deposit
-                let transferred = self.env().transferred_value();
-                if transferred != swap.counterparty_asset {
-                    return Err(Error::InsufficientCounterpartyBalance);
+                // Pull in the counterparty's leg
+                match swap.counterparty_asset {
+                    AssetKind::Native(amount) => {
+                        if self.env().transferred_value() != amount {
+                            return Err(Error::InsufficientCounterpartyBalance);
+                        }
+                    }
+                    AssetKind::Psp22 { token, amount } => {
+                        if self.env().transferred_value() != 0 {
+                            return Err(Error::UnexpectedValueTransferred);
+                        }
+                        self.escrow_psp22(token, caller, amount)?;
+                    }
+                }
+
+                // Execute asset exchange, skimming the protocol fee from each leg
+                let initiator_fee = self.release_asset_with_fee(swap.initiator_asset, swap.counterparty)?;
+                let counterparty_fee = self.release_asset_with_fee(swap.counterparty_asset, swap.initiator)?;
+
+                swap.status = SwapStatus::Completed;
+                self.swaps.insert(swap_id, &swap);
+
+                self.emit_swap_accepted(swap_id, initiator_fee, counterparty_fee);
+
+                Ok(())
+            })();
+            self.exit_reentrancy_guard();
+            result
+        }
+
+        /// Claims a hashed-timelock (HTLC) swap by revealing its preimage
+        /// # Arguments
+        /// - swap_id: ID of the HTLC swap to claim
+        /// - preimage: Secret whose Keccak256 hash must equal the swap's hashlock
+        /// # Returns
+        /// - Ok(()): Success, initiator's deposit released to the caller
+        /// - Err(Error): Failure reason
+        /// # Note
+        /// Revealing the preimage on-chain lets the counterparty on the other
+        /// chain observe it and claim their own leg of the atomic swap. The
+        /// protocol fee is skimmed here too, same as `accept_swap`.
+        #[ink(message)]
+        pub fn claim(&mut self, swap_id: u32, preimage: Vec<u8>) -> Result<(), Error> {
+            self.enter_reentrancy_guard()?;
+            let result = (|| {
+                let mut swap = self.swaps.get(swap_id).ok_or(Error::SwapNotFound)?;
+                let hashlock = swap.hashlock.ok_or(Error::NotHtlcSwap)?;
+
+                if swap.status != SwapStatus::Funded {
+                    return Err(Error::SwapNotFunded);
+                }
+
+                let digest = self.env().hash_bytes::<Keccak256>(&preimage);
+                if digest != hashlock {
+                    return Err(Error::InvalidPreimage);
                 }
 
-                // Execute asset exchange
-                self.transfer_funds(swap.initiator, transferred)?;
-                self.transfer_funds(swap.counterparty, swap.initiator_asset)?;
+                // Release the initiator's deposit to whoever revealed the secret,
+                // skimming the protocol fee like any other completed swap
+                let fee = self.release_asset_with_fee(swap.initiator_asset, self.env().caller())?;
 
-                // Cleanup storage
-                self.swaps.remove(swap_id);
+                swap.status = SwapStatus::Completed;
+                self.swaps.insert(swap_id, &swap);
 
-                self.emit_swap_accepted(swap_id);
+                self.emit_swap_claimed(swap_id, preimage, fee);
 
                 Ok(())
             })();
@@ -232,7 +765,7 @@ mod swap_contract {
         pub fn cancel_swap(&mut self, swap_id: u32) -> Result<(), Error> {
             self.enter_reentrancy_guard()?;
             let result = (|| {
-                let swap = self.swaps.get(swap_id).ok_or(Error::SwapNotFound)?;
+                let mut swap = self.swaps.get(swap_id).ok_or(Error::SwapNotFound)?;
                 let caller = self.env().caller();
 
                 // Authorization check
@@ -240,11 +773,29 @@ mod swap_contract {
                     return Err(Error::NotAuthorized);
                 }
 
-                // Return initiator's funds
-                self.transfer_funds(swap.initiator, swap.initiator_asset)?;
+                match swap.status {
+                    SwapStatus::Pending => {
+                        // Nothing was ever escrowed, so there is nothing to return
+                    }
+                    SwapStatus::Funded => {
+                        // HTLC swaps may only be reclaimed once their timelock has
+                        // expired, so a watcher has a guaranteed window to observe
+                        // a claimed preimage
+                        if let Some(timelock) = swap.timelock {
+                            if self.env().block_number() < timelock {
+                                return Err(Error::TimelockNotExpired);
+                            }
+                        }
 
-                // Cleanup storage
-                self.swaps.remove(swap_id);
+                        self.release_asset(swap.initiator_asset, swap.initiator)?;
+                    }
+                    SwapStatus::Completed | SwapStatus::Cancelled => {
+                        return Err(Error::SwapNotFound);
+                    }
+                }
+
+                swap.status = SwapStatus::Cancelled;
+                self.swaps.insert(swap_id, &swap);
 
                 self.emit_swap_cancelled(swap_id);
 
@@ -254,6 +805,46 @@ mod swap_contract {
             result
         }
 
+        /// Returns a swap by ID, including completed and cancelled ones;
+        /// check its `status` for where it sits in the swap lifecycle
+        #[ink(message)]
+        pub fn get_swap(&self, swap_id: u32) -> Option<Swap> {
+            self.swaps.get(swap_id)
+        }
+
+        /// Returns the ID that will be assigned to the next counter-based swap
+        #[ink(message)]
+        pub fn get_next_swap_id(&self) -> u32 {
+            self.next_swap_id
+        }
+
+        /// Returns up to `limit` swaps starting at `start`, including completed
+        /// and cancelled ones
+        /// # Arguments
+        /// - start: First swap ID to consider
+        /// - limit: Maximum number of IDs to scan, bounding the gas used
+        #[ink(message)]
+        pub fn list_swaps(&self, start: u32, limit: u32) -> Vec<(u32, Swap)> {
+            let end = start.saturating_add(limit).min(self.next_swap_id);
+            let mut swaps = Vec::new();
+            let mut id = start;
+            while id < end {
+                if let Some(swap) = self.swaps.get(id) {
+                    swaps.push((id, swap));
+                }
+                id += 1;
+            }
+            swaps
+        }
+
+        /// Returns every swap ID where `account` was the initiator or the counterparty
+        #[ink(message)]
+        pub fn swaps_for(&self, account: AccountId) -> Vec<u32> {
+            let mut ids = self.swaps_by_initiator.get(account).unwrap_or_default();
+            ids.extend(self.swaps_by_counterparty.get(account).unwrap_or_default());
+            ids
+        }
+
         fn enter_reentrancy_guard(&mut self) -> Result<(), Error> {
             if self.reentrancy_guard {
                 return Err(Error::Reentrancy);
@@ -296,16 +887,79 @@ mod swap_contract {
             test::set_caller::<DefaultEnvironment>(accounts.alice);
             test::set_value_transferred::<DefaultEnvironment>(100);
             
-            let result = contract.initiate_swap(accounts.bob, 200);
+            let result = contract.initiate_swap(accounts.bob, AssetKind::Native(100), AssetKind::Native(200));
             assert!(result.is_ok());
-            
+
             let swap_id = result.unwrap();
             let swap = contract.swaps.get(swap_id).unwrap();
-            
+
             assert_eq!(swap.initiator, accounts.alice);
             assert_eq!(swap.counterparty, accounts.bob);
-            assert_eq!(swap.initiator_asset, 100);
-            assert_eq!(swap.counterparty_asset, 200);
+            assert_eq!(swap.initiator_asset, AssetKind::Native(100));
+            assert_eq!(swap.counterparty_asset, AssetKind::Native(200));
+            assert_eq!(swap.status, SwapStatus::Pending);
+        }
+
+        #[ink::test]
+        fn fund_swap_makes_pending_swap_funded() {
+            let mut contract = SwapContract::new();
+            let accounts = get_accounts();
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let swap_id = contract
+                .initiate_swap(accounts.bob, AssetKind::Native(100), AssetKind::Native(200))
+                .unwrap();
+
+            test::set_value_transferred::<DefaultEnvironment>(100);
+            assert!(contract.fund_swap(swap_id).is_ok());
+            assert_eq!(contract.swaps.get(swap_id).unwrap().status, SwapStatus::Funded);
+        }
+
+        #[ink::test]
+        fn fund_swap_fails_wrong_amount() {
+            let mut contract = SwapContract::new();
+            let accounts = get_accounts();
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let swap_id = contract
+                .initiate_swap(accounts.bob, AssetKind::Native(100), AssetKind::Native(200))
+                .unwrap();
+
+            test::set_value_transferred::<DefaultEnvironment>(50);
+            assert_eq!(
+                contract.fund_swap(swap_id),
+                Err(Error::InsufficientInitiatorBalance)
+            );
+        }
+
+        #[ink::test]
+        fn fund_swap_fails_non_initiator() {
+            let mut contract = SwapContract::new();
+            let accounts = get_accounts();
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let swap_id = contract
+                .initiate_swap(accounts.bob, AssetKind::Native(100), AssetKind::Native(200))
+                .unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            test::set_value_transferred::<DefaultEnvironment>(100);
+            assert_eq!(contract.fund_swap(swap_id), Err(Error::NotAuthorized));
+        }
+
+        #[ink::test]
+        fn fund_swap_fails_on_cancelled_swap() {
+            let mut contract = SwapContract::new();
+            let accounts = get_accounts();
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let swap_id = contract
+                .initiate_swap(accounts.bob, AssetKind::Native(100), AssetKind::Native(200))
+                .unwrap();
+            contract.cancel_swap(swap_id).unwrap();
+
+            test::set_value_transferred::<DefaultEnvironment>(100);
+            assert_eq!(contract.fund_swap(swap_id), Err(Error::SwapNotFound));
         }
 
         #[ink::test]
@@ -313,17 +967,33 @@ mod swap_contract {
             let mut contract = SwapContract::new();
             let accounts = get_accounts();
 
-            // Setup swap
+            // Setup and fund swap
             test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let swap_id = contract.initiate_swap(accounts.bob, AssetKind::Native(100), AssetKind::Native(200)).unwrap();
             test::set_value_transferred::<DefaultEnvironment>(100);
-            let swap_id = contract.initiate_swap(accounts.bob, 200).unwrap();
+            contract.fund_swap(swap_id).unwrap();
 
             // Bob accepts swap
             test::set_caller::<DefaultEnvironment>(accounts.bob);
             test::set_value_transferred::<DefaultEnvironment>(200);
-            
+
             assert!(contract.accept_swap(swap_id).is_ok());
-            assert!(contract.swaps.get(swap_id).is_none());
+            assert_eq!(contract.swaps.get(swap_id).unwrap().status, SwapStatus::Completed);
+        }
+
+        #[ink::test]
+        fn cancel_pending_swap_succeeds_without_funding() {
+            let mut contract = SwapContract::new();
+            let accounts = get_accounts();
+
+            // Setup swap, never funded
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let swap_id = contract.initiate_swap(accounts.bob, AssetKind::Native(100), AssetKind::Native(200)).unwrap();
+
+            // Alice cancels swap with no transfer at all
+            test::set_value_transferred::<DefaultEnvironment>(0);
+            assert!(contract.cancel_swap(swap_id).is_ok());
+            assert_eq!(contract.swaps.get(swap_id).unwrap().status, SwapStatus::Cancelled);
         }
 
         #[ink::test]
@@ -331,14 +1001,15 @@ mod swap_contract {
             let mut contract = SwapContract::new();
             let accounts = get_accounts();
 
-            // Setup swap
+            // Setup and fund swap
             test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let swap_id = contract.initiate_swap(accounts.bob, AssetKind::Native(100), AssetKind::Native(200)).unwrap();
             test::set_value_transferred::<DefaultEnvironment>(100);
-            let swap_id = contract.initiate_swap(accounts.bob, 200).unwrap();
+            contract.fund_swap(swap_id).unwrap();
 
-            // Alice cancels swap
+            // Alice cancels funded swap
             assert!(contract.cancel_swap(swap_id).is_ok());
-            assert!(contract.swaps.get(swap_id).is_none());
+            assert_eq!(contract.swaps.get(swap_id).unwrap().status, SwapStatus::Cancelled);
         }
 
         #[ink::test]
@@ -347,13 +1018,14 @@ mod swap_contract {
             let accounts = get_accounts();
 
             test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let swap_id = contract.initiate_swap(accounts.bob, AssetKind::Native(100), AssetKind::Native(200)).unwrap();
             test::set_value_transferred::<DefaultEnvironment>(100);
-            let swap_id = contract.initiate_swap(accounts.bob, 200).unwrap();
+            contract.fund_swap(swap_id).unwrap();
 
             // Charlie tries to accept
             test::set_caller::<DefaultEnvironment>(accounts.charlie);
             test::set_value_transferred::<DefaultEnvironment>(200);
-            
+
             assert_eq!(contract.accept_swap(swap_id), Err(Error::NotAuthorized));
         }
 
@@ -363,8 +1035,7 @@ mod swap_contract {
             let accounts = get_accounts();
 
             test::set_caller::<DefaultEnvironment>(accounts.alice);
-            test::set_value_transferred::<DefaultEnvironment>(100);
-            let swap_id = contract.initiate_swap(accounts.bob, 200).unwrap();
+            let swap_id = contract.initiate_swap(accounts.bob, AssetKind::Native(100), AssetKind::Native(200)).unwrap();
 
             // Bob tries to cancel
             test::set_caller::<DefaultEnvironment>(accounts.bob);
@@ -377,13 +1048,14 @@ mod swap_contract {
             let accounts = get_accounts();
 
             test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let swap_id = contract.initiate_swap(accounts.bob, AssetKind::Native(100), AssetKind::Native(200)).unwrap();
             test::set_value_transferred::<DefaultEnvironment>(100);
-            let swap_id = contract.initiate_swap(accounts.bob, 200).unwrap();
+            contract.fund_swap(swap_id).unwrap();
 
             // Bob sends wrong amount
             test::set_caller::<DefaultEnvironment>(accounts.bob);
             test::set_value_transferred::<DefaultEnvironment>(150);
-            
+
             assert_eq!(contract.accept_swap(swap_id), Err(Error::InsufficientCounterpartyBalance));
         }
 
@@ -393,10 +1065,9 @@ mod swap_contract {
             let accounts = get_accounts();
 
             test::set_caller::<DefaultEnvironment>(accounts.alice);
-            test::set_value_transferred::<DefaultEnvironment>(0);
-            
+
             assert_eq!(
-                contract.initiate_swap(accounts.bob, 200),
+                contract.initiate_swap(accounts.bob, AssetKind::Native(0), AssetKind::Native(200)),
                 Err(Error::InsufficientInitiatorBalance)
             );
         }
@@ -406,17 +1077,406 @@ mod swap_contract {
             let mut contract = SwapContract::new();
             let accounts = get_accounts();
 
-            // Setup and accept swap
+            // Setup, fund and accept swap
             test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let swap_id = contract.initiate_swap(accounts.bob, AssetKind::Native(100), AssetKind::Native(200)).unwrap();
             test::set_value_transferred::<DefaultEnvironment>(100);
-            let swap_id = contract.initiate_swap(accounts.bob, 200).unwrap();
+            contract.fund_swap(swap_id).unwrap();
 
             test::set_caller::<DefaultEnvironment>(accounts.bob);
             test::set_value_transferred::<DefaultEnvironment>(200);
             contract.accept_swap(swap_id).unwrap();
 
             // Try to accept again
-            assert_eq!(contract.accept_swap(swap_id), Err(Error::SwapNotFound));
+            assert_eq!(contract.accept_swap(swap_id), Err(Error::SwapNotFunded));
+        }
+
+        fn hash_preimage(preimage: &[u8]) -> [u8; 32] {
+            let mut digest = <Keccak256 as HashOutput>::Type::default();
+            ink::env::hash_bytes::<Keccak256>(preimage, &mut digest);
+            digest
+        }
+
+        #[ink::test]
+        fn initiate_htlc_swap_works() {
+            let mut contract = SwapContract::new();
+            let accounts = get_accounts();
+            let hashlock = hash_preimage(b"secret");
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            test::set_value_transferred::<DefaultEnvironment>(100);
+
+            let swap_id = contract
+                .initiate_htlc_swap(accounts.bob, 200, hashlock, 10)
+                .unwrap();
+
+            let swap = contract.swaps.get(swap_id).unwrap();
+            assert_eq!(swap.hashlock, Some(hashlock));
+            assert_eq!(swap.timelock, Some(10));
+        }
+
+        #[ink::test]
+        fn claim_htlc_swap_success() {
+            let mut contract = SwapContract::new();
+            let accounts = get_accounts();
+            let preimage = b"secret".to_vec();
+            let hashlock = hash_preimage(&preimage);
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            test::set_value_transferred::<DefaultEnvironment>(100);
+            let swap_id = contract
+                .initiate_htlc_swap(accounts.bob, 200, hashlock, 10)
+                .unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert!(contract.claim(swap_id, preimage).is_ok());
+            assert_eq!(contract.swaps.get(swap_id).unwrap().status, SwapStatus::Completed);
+        }
+
+        #[ink::test]
+        fn claim_htlc_swap_skims_protocol_fee() {
+            let accounts = get_accounts();
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut contract = SwapContract::new_with_fee(500, accounts.eve).unwrap();
+            let preimage = b"secret".to_vec();
+            let hashlock = hash_preimage(&preimage);
+
+            test::set_value_transferred::<DefaultEnvironment>(100);
+            let swap_id = contract
+                .initiate_htlc_swap(accounts.bob, 200, hashlock, 10)
+                .unwrap();
+
+            let fee_recipient_balance_before =
+                test::get_account_balance::<DefaultEnvironment>(accounts.eve).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert!(contract.claim(swap_id, preimage).is_ok());
+
+            let fee_recipient_balance_after =
+                test::get_account_balance::<DefaultEnvironment>(accounts.eve).unwrap();
+
+            // 5% of the initiator's 100 deposit
+            assert_eq!(fee_recipient_balance_after - fee_recipient_balance_before, 5);
+        }
+
+        #[ink::test]
+        fn claim_htlc_swap_fails_wrong_preimage() {
+            let mut contract = SwapContract::new();
+            let accounts = get_accounts();
+            let hashlock = hash_preimage(b"secret");
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            test::set_value_transferred::<DefaultEnvironment>(100);
+            let swap_id = contract
+                .initiate_htlc_swap(accounts.bob, 200, hashlock, 10)
+                .unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                contract.claim(swap_id, b"wrong".to_vec()),
+                Err(Error::InvalidPreimage)
+            );
+        }
+
+        #[ink::test]
+        fn accept_swap_fails_on_htlc_swap() {
+            let mut contract = SwapContract::new();
+            let accounts = get_accounts();
+            let hashlock = hash_preimage(b"secret");
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            test::set_value_transferred::<DefaultEnvironment>(100);
+            let swap_id = contract
+                .initiate_htlc_swap(accounts.bob, 200, hashlock, 10)
+                .unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            test::set_value_transferred::<DefaultEnvironment>(200);
+            assert_eq!(contract.accept_swap(swap_id), Err(Error::NotHtlcSwap));
+        }
+
+        #[ink::test]
+        fn cancel_htlc_swap_fails_before_timelock() {
+            let mut contract = SwapContract::new();
+            let accounts = get_accounts();
+            let hashlock = hash_preimage(b"secret");
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            test::set_value_transferred::<DefaultEnvironment>(100);
+            let swap_id = contract
+                .initiate_htlc_swap(accounts.bob, 200, hashlock, 10)
+                .unwrap();
+
+            assert_eq!(
+                contract.cancel_swap(swap_id),
+                Err(Error::TimelockNotExpired)
+            );
+        }
+
+        #[ink::test]
+        fn cancel_htlc_swap_succeeds_after_timelock() {
+            let mut contract = SwapContract::new();
+            let accounts = get_accounts();
+            let hashlock = hash_preimage(b"secret");
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            test::set_value_transferred::<DefaultEnvironment>(100);
+            let swap_id = contract
+                .initiate_htlc_swap(accounts.bob, 200, hashlock, 10)
+                .unwrap();
+
+            test::set_block_number::<DefaultEnvironment>(10);
+            assert!(contract.cancel_swap(swap_id).is_ok());
+        }
+
+        #[ink::test]
+        fn initiate_swap_fails_zero_psp22_deposit() {
+            let mut contract = SwapContract::new();
+            let accounts = get_accounts();
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+
+            assert_eq!(
+                contract.initiate_swap(
+                    accounts.bob,
+                    AssetKind::Psp22 { token: accounts.django, amount: 0 },
+                    AssetKind::Native(200)
+                ),
+                Err(Error::InsufficientInitiatorBalance)
+            );
+        }
+
+        // `ink::env::test`'s off-chain environment doesn't support invoking
+        // another contract (it panics with `unimplemented!` from
+        // `invoke_contract`), so `escrow_psp22`'s cross-contract path
+        // (`psp22_allowance`/`psp22_transfer_from`/`psp22_transfer`) can't be
+        // exercised under `#[ink::test]` at all, successful or not; that
+        // needs an `ink_e2e` test against a deployed PSP22 token, which this
+        // crate does not yet have. The guards below don't reach that call.
+        #[ink::test]
+        fn fund_swap_fails_unexpected_value_on_psp22_leg() {
+            let mut contract = SwapContract::new();
+            let accounts = get_accounts();
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let swap_id = contract
+                .initiate_swap(
+                    accounts.bob,
+                    AssetKind::Psp22 { token: accounts.django, amount: 100 },
+                    AssetKind::Native(200),
+                )
+                .unwrap();
+
+            test::set_value_transferred::<DefaultEnvironment>(1);
+            assert_eq!(
+                contract.fund_swap(swap_id),
+                Err(Error::UnexpectedValueTransferred)
+            );
+        }
+
+        #[ink::test]
+        fn accept_swap_fails_unexpected_value_on_psp22_leg() {
+            let mut contract = SwapContract::new();
+            let accounts = get_accounts();
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let swap_id = contract
+                .initiate_swap(
+                    accounts.bob,
+                    AssetKind::Native(100),
+                    AssetKind::Psp22 { token: accounts.django, amount: 200 },
+                )
+                .unwrap();
+            test::set_value_transferred::<DefaultEnvironment>(100);
+            contract.fund_swap(swap_id).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            test::set_value_transferred::<DefaultEnvironment>(1);
+            assert_eq!(
+                contract.accept_swap(swap_id),
+                Err(Error::UnexpectedValueTransferred)
+            );
+        }
+
+        #[ink::test]
+        fn new_with_fee_rejects_too_high_fee() {
+            let accounts = get_accounts();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+
+            assert_eq!(
+                SwapContract::new_with_fee(MAX_FEE_BPS + 1, accounts.eve).err(),
+                Some(Error::FeeTooHigh)
+            );
+        }
+
+        #[ink::test]
+        fn set_fee_fails_for_non_owner() {
+            let mut contract = SwapContract::new();
+            let accounts = get_accounts();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                contract.set_fee(100, accounts.eve),
+                Err(Error::NotAuthorized)
+            );
+        }
+
+        #[ink::test]
+        fn accept_swap_skims_protocol_fee() {
+            let accounts = get_accounts();
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut contract = SwapContract::new_with_fee(500, accounts.eve).unwrap();
+
+            let swap_id = contract
+                .initiate_swap(accounts.bob, AssetKind::Native(100), AssetKind::Native(200))
+                .unwrap();
+            test::set_value_transferred::<DefaultEnvironment>(100);
+            contract.fund_swap(swap_id).unwrap();
+
+            let fee_recipient_balance_before =
+                test::get_account_balance::<DefaultEnvironment>(accounts.eve).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            test::set_value_transferred::<DefaultEnvironment>(200);
+            assert!(contract.accept_swap(swap_id).is_ok());
+
+            let fee_recipient_balance_after =
+                test::get_account_balance::<DefaultEnvironment>(accounts.eve).unwrap();
+
+            // 5% of the initiator's 100 plus 5% of the counterparty's 200
+            assert_eq!(fee_recipient_balance_after - fee_recipient_balance_before, 15);
+        }
+
+        #[ink::test]
+        fn get_swap_and_get_next_swap_id_work() {
+            let mut contract = SwapContract::new();
+            let accounts = get_accounts();
+
+            assert_eq!(contract.get_next_swap_id(), 0);
+            assert_eq!(contract.get_swap(0), None);
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            test::set_value_transferred::<DefaultEnvironment>(100);
+            let swap_id = contract
+                .initiate_swap(accounts.bob, AssetKind::Native(100), AssetKind::Native(200))
+                .unwrap();
+
+            assert_eq!(contract.get_next_swap_id(), 1);
+            assert_eq!(contract.get_swap(swap_id).unwrap().initiator, accounts.alice);
+        }
+
+        #[ink::test]
+        fn list_swaps_is_bounded_by_limit() {
+            let mut contract = SwapContract::new();
+            let accounts = get_accounts();
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            for _ in 0..3 {
+                test::set_value_transferred::<DefaultEnvironment>(100);
+                contract
+                    .initiate_swap(accounts.bob, AssetKind::Native(100), AssetKind::Native(200))
+                    .unwrap();
+            }
+
+            let page = contract.list_swaps(0, 2);
+            assert_eq!(page.len(), 2);
+            assert_eq!(page[0].0, 0);
+            assert_eq!(page[1].0, 1);
+        }
+
+        #[ink::test]
+        fn list_swaps_includes_cancelled_swaps_with_status() {
+            let mut contract = SwapContract::new();
+            let accounts = get_accounts();
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let swap_id = contract
+                .initiate_swap(accounts.bob, AssetKind::Native(100), AssetKind::Native(200))
+                .unwrap();
+            contract.cancel_swap(swap_id).unwrap();
+
+            let page = contract.list_swaps(0, 10);
+            assert_eq!(page.len(), 1);
+            assert_eq!(page[0].0, swap_id);
+            assert_eq!(page[0].1.status, SwapStatus::Cancelled);
+        }
+
+        #[ink::test]
+        fn swaps_for_includes_both_roles() {
+            let mut contract = SwapContract::new();
+            let accounts = get_accounts();
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            test::set_value_transferred::<DefaultEnvironment>(100);
+            let swap_id = contract
+                .initiate_swap(accounts.bob, AssetKind::Native(100), AssetKind::Native(200))
+                .unwrap();
+
+            assert_eq!(contract.swaps_for(accounts.alice), ink::prelude::vec![swap_id]);
+            assert_eq!(contract.swaps_for(accounts.bob), ink::prelude::vec![swap_id]);
+            assert!(contract.swaps_for(accounts.charlie).is_empty());
+        }
+
+        #[ink::test]
+        fn initiate_swap_with_salt_is_reachable_by_salt() {
+            let mut contract = SwapContract::new();
+            let accounts = get_accounts();
+            let salt = [7u8; 32];
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let swap_id = contract
+                .initiate_swap_with_salt(
+                    accounts.bob,
+                    AssetKind::Native(100),
+                    AssetKind::Native(200),
+                    salt,
+                )
+                .unwrap();
+
+            assert_eq!(
+                contract.swap_id_for_salt(accounts.alice, accounts.bob, salt),
+                Some(swap_id)
+            );
+        }
+
+        #[ink::test]
+        fn initiate_swap_with_salt_rejects_duplicate() {
+            let mut contract = SwapContract::new();
+            let accounts = get_accounts();
+            let salt = [7u8; 32];
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract
+                .initiate_swap_with_salt(
+                    accounts.bob,
+                    AssetKind::Native(100),
+                    AssetKind::Native(200),
+                    salt,
+                )
+                .unwrap();
+
+            assert_eq!(
+                contract.initiate_swap_with_salt(
+                    accounts.bob,
+                    AssetKind::Native(100),
+                    AssetKind::Native(200),
+                    salt,
+                ),
+                Err(Error::SwapAlreadyExists)
+            );
+        }
+
+        #[ink::test]
+        fn swap_id_for_salt_is_none_when_not_negotiated() {
+            let contract = SwapContract::new();
+            let accounts = get_accounts();
+
+            assert_eq!(
+                contract.swap_id_for_salt(accounts.alice, accounts.bob, [0u8; 32]),
+                None
+            );
         }
     }
 }
\ No newline at end of file